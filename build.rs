@@ -0,0 +1,38 @@
+use std::fs;
+use std::path::Path;
+
+/// Computes a simple FNV-1a hash over the contents of every `.rs` file directly
+/// inside `src/`, so the resulting digest changes whenever one of those files
+/// changes. Subdirectories of `src/` are not read.
+fn digest_src(dir: &Path) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+    const FNV_PRIME: u64 = 0x100000001b3;
+
+    let mut hash = FNV_OFFSET;
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .expect("src directory must exist")
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        if path.extension().and_then(|ext| ext.to_str()) != Some("rs") {
+            continue;
+        }
+        let contents = fs::read(&path).expect("readable source file");
+        for byte in contents {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    hash
+}
+
+fn main() {
+    let src_dir = Path::new("src");
+    let digest = digest_src(src_dir);
+    println!("cargo:rustc-env=RUSSO_BUILD_DIGEST={:016x}", digest);
+    println!("cargo:rerun-if-changed=src");
+}
@@ -0,0 +1,12 @@
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+
+create_exception!(russo, RussoError, PyException);
+
+/// Register `RussoError` on the top-level `russo` module.
+pub fn register_module(_py: Python, parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    parent.add("RussoError", parent.py().get_type::<RussoError>())?;
+    Ok(())
+}
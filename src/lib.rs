@@ -1,19 +1,46 @@
 use pyo3::prelude::*;
 
-// Core Rust functions (callable from Rust)
-pub fn add(a: i64, b: i64) -> i64 {
-    a + b
+mod error;
+mod math;
+
+/// Configure the verbosity of Rust-side logging that is forwarded to
+/// Python's `logging` module via `pyo3_log`.
+///
+/// `level` is one of "debug", "info", "warn", or "error" (case-insensitive).
+/// Unrecognized values fall back to `info`.
+#[pyfunction]
+#[pyo3(text_signature = "(level, /)")]
+fn configure_logging(level: &str) {
+    let filter = match level.to_lowercase().as_str() {
+        "debug" => log::LevelFilter::Debug,
+        "info" => log::LevelFilter::Info,
+        "warn" => log::LevelFilter::Warn,
+        "error" => log::LevelFilter::Error,
+        _ => log::LevelFilter::Info,
+    };
+    log::set_max_level(filter);
 }
 
-// Python-exposed functions
+/// Return a digest computed at compile time from the crate's Rust sources.
+///
+/// Python startup code can compare this against a value recorded at build
+/// time to detect an import of a stale compiled extension.
 #[pyfunction]
-fn py_add(a: i64, b: i64) -> i64 {
-    add(a, b)
+#[pyo3(text_signature = "()")]
+fn rust_build_digest() -> &'static str {
+    env!("RUSSO_BUILD_DIGEST")
 }
 
 // Python module definition
 #[pymodule]
-fn russo(m: &Bound<'_, PyModule>) -> PyResult<()> {
-    m.add_function(wrap_pyfunction!(py_add, m)?)?;
+fn russo(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+    pyo3_log::init();
+    m.add_function(wrap_pyfunction!(configure_logging, m)?)?;
+    m.add_function(wrap_pyfunction!(rust_build_digest, m)?)?;
+    m.add("__doc__", "russo: a Python extension module implemented in Rust.")?;
+
+    error::register_module(py, m)?;
+    math::register_module(py, m)?;
+
     Ok(())
 }
@@ -0,0 +1,111 @@
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+
+use crate::error::RussoError;
+
+// Core Rust functions (callable from Rust)
+pub fn add(a: i64, b: i64) -> Option<i64> {
+    log::trace!("add(a={}, b={})", a, b);
+    let result = a.checked_add(b);
+    match result {
+        Some(sum) => log::debug!("add({}, {}) = {}", a, b, sum),
+        None => log::debug!("add({}, {}) overflowed", a, b),
+    }
+    result
+}
+
+/// Add two integers, raising `russo.RussoError` on overflow.
+#[pyfunction]
+#[pyo3(text_signature = "(a, b, /)")]
+fn py_add(a: i64, b: i64) -> PyResult<i64> {
+    add(a, b).ok_or_else(|| RussoError::new_err(format!("overflow computing {} + {}", a, b)))
+}
+
+/// Sum an arithmetic progression of `n` terms starting at `start` and
+/// increasing by `step` each term.
+pub fn sum_range(n: i64, start: i64, step: i64) -> Option<i64> {
+    log::trace!("sum_range(n={}, start={}, step={})", n, start, step);
+    // Intermediate products are computed in i128 so that only a sum outside
+    // the i64 range is reported as overflow.
+    let (n128, start128, step128) = (i128::from(n), i128::from(start), i128::from(step));
+    let step_total = step128.checked_mul((n128 - 1) * n128 / 2)?;
+    let result = (n128 * start128)
+        .checked_add(step_total)
+        .and_then(|sum| i64::try_from(sum).ok());
+    match result {
+        Some(sum) => log::debug!("sum_range({}, {}, {}) = {}", n, start, step, sum),
+        None => log::debug!("sum_range({}, {}, {}) overflowed", n, start, step),
+    }
+    result
+}
+
+/// Sum an arithmetic progression of `n` terms, raising `russo.RussoError`
+/// on overflow.
+#[pyfunction(name = "sum_range")]
+#[pyo3(signature = (n, start=1, step=1))]
+#[pyo3(text_signature = "(n, start=1, step=1)")]
+fn py_sum_range(n: i64, start: i64, step: i64) -> PyResult<i64> {
+    sum_range(n, start, step)
+        .ok_or_else(|| RussoError::new_err(format!("overflow summing range({}, {}, {})", n, start, step)))
+}
+
+/// Build the `russo.math` submodule and attach it to `parent`.
+pub fn register_module(py: Python, parent: &Bound<'_, PyModule>) -> PyResult<()> {
+    let math = PyModule::new(py, "math")?;
+    math.add_function(wrap_pyfunction!(py_add, &math)?)?;
+    math.add_function(wrap_pyfunction!(py_sum_range, &math)?)?;
+
+    parent.add_submodule(&math)?;
+    py.import("sys")?
+        .getattr("modules")?
+        .set_item("russo.math", &math)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+    use pyo3::Python;
+
+    #[test]
+    fn py_add_has_text_signature() {
+        Python::with_gil(|py| {
+            let func = wrap_pyfunction!(py_add, py).unwrap();
+            let sig: String = func
+                .getattr("__text_signature__")
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(sig, "(a, b, /)");
+        });
+    }
+
+    #[test]
+    fn sum_range_positional() {
+        Python::with_gil(|py| {
+            let func = wrap_pyfunction!(py_sum_range, py).unwrap();
+            let result: i64 = func.call1((5, 1, 1)).unwrap().extract().unwrap();
+            assert_eq!(result, 15); // 1 + 2 + 3 + 4 + 5
+        });
+    }
+
+    #[test]
+    fn sum_range_keyword_with_defaults() {
+        Python::with_gil(|py| {
+            let func = wrap_pyfunction!(py_sum_range, py).unwrap();
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("n", 5).unwrap();
+            let result: i64 = func.call((), Some(&kwargs)).unwrap().extract().unwrap();
+            assert_eq!(result, 15); // start=1, step=1 by default
+
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("n", 4).unwrap();
+            kwargs.set_item("start", 2).unwrap();
+            kwargs.set_item("step", 3).unwrap();
+            let result: i64 = func.call((), Some(&kwargs)).unwrap().extract().unwrap();
+            assert_eq!(result, 2 + 5 + 8 + 11);
+        });
+    }
+}